@@ -0,0 +1,75 @@
+/* Byte and multi-byte access to memory
+ *
+ * Benchmarks for the bulk-copy add/read paths
+ *
+ * (c) Chris Williams, 2020.
+ *
+ * See LICENSE for usage and copying.
+ */
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use byterider::Bytes;
+
+/* number of words to add/read per iteration */
+const WORD_COUNT: usize = 4096;
+
+fn add_u32(c: &mut Criterion)
+{
+    c.bench_function("add_u32", |b| b.iter(||
+    {
+        let mut bytes = Bytes::new();
+        for word in 0..WORD_COUNT
+        {
+            bytes.add_u32(word as u32);
+        }
+    }));
+}
+
+fn read_u32(c: &mut Criterion)
+{
+    let mut bytes = Bytes::new();
+    for word in 0..WORD_COUNT
+    {
+        bytes.add_u32(word as u32);
+    }
+
+    c.bench_function("read_u32", |b| b.iter(||
+    {
+        for offset in 0..WORD_COUNT
+        {
+            bytes.read_u32(offset * 4).unwrap();
+        }
+    }));
+}
+
+fn add_u128(c: &mut Criterion)
+{
+    c.bench_function("add_u128", |b| b.iter(||
+    {
+        let mut bytes = Bytes::new();
+        for word in 0..WORD_COUNT
+        {
+            bytes.add_u128(word as u128);
+        }
+    }));
+}
+
+fn read_u128(c: &mut Criterion)
+{
+    let mut bytes = Bytes::new();
+    for word in 0..WORD_COUNT
+    {
+        bytes.add_u128(word as u128);
+    }
+
+    c.bench_function("read_u128", |b| b.iter(||
+    {
+        for offset in 0..WORD_COUNT
+        {
+            bytes.read_u128(offset * 16).unwrap();
+        }
+    }));
+}
+
+criterion_group!(benches, add_u32, read_u32, add_u128, read_u128);
+criterion_main!(benches);
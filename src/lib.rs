@@ -16,6 +16,7 @@
 
 extern crate alloc;
 use alloc::vec::Vec;
+use core::convert::TryInto;
 use core::mem::size_of;
 
 #[cfg(test)]
@@ -29,6 +30,51 @@ pub enum Ordering
     BigEndian
 }
 
+/* selects a byte ordering at compile time rather than as a runtime Ordering value, so the
+swap is monomorphized. each Bytes *_as call takes its own order type, so the order is not
+fixed per buffer: successive calls may use different orders. implemented by the
+zero-sized LittleEndian and BigEndian types below */
+pub trait ByteOrder
+{
+    fn order_u16(value: u16) -> u16;
+    fn order_u32(value: u32) -> u32;
+    fn order_u64(value: u64) -> u64;
+    fn order_u128(value: u128) -> u128;
+}
+
+/* zero-sized marker selecting little-endian byte order at compile time */
+#[derive(Clone, Copy)]
+pub struct LittleEndian;
+
+impl ByteOrder for LittleEndian
+{
+    fn order_u16(value: u16) -> u16 { value.to_le() }
+    fn order_u32(value: u32) -> u32 { value.to_le() }
+    fn order_u64(value: u64) -> u64 { value.to_le() }
+    fn order_u128(value: u128) -> u128 { value.to_le() }
+}
+
+/* zero-sized marker selecting big-endian byte order at compile time */
+#[derive(Clone, Copy)]
+pub struct BigEndian;
+
+impl ByteOrder for BigEndian
+{
+    fn order_u16(value: u16) -> u16 { value.to_be() }
+    fn order_u32(value: u32) -> u32 { value.to_be() }
+    fn order_u64(value: u64) -> u64 { value.to_be() }
+    fn order_u128(value: u128) -> u128 { value.to_be() }
+}
+
+/* network byte order is always big-endian, by convention */
+pub type NetworkEndian = BigEndian;
+
+/* native byte order is whichever ordering this host uses */
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
 pub struct Bytes
 {
     ordering: Ordering,
@@ -62,7 +108,7 @@ impl Bytes
     {
         let mut b = Bytes::new();
         b.data = bytes.to_vec();
-        return b;
+        b
     }
 
     /* access the data as a borrowed immutable slice */
@@ -74,10 +120,24 @@ impl Bytes
     /* return the length of the array in bytes */
     pub fn len(&self) -> usize { self.data.len() }
 
+    /* return true if the array holds no bytes */
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
     /* return offsets into the top-most byte */
     pub fn offset32(&self) -> u32 { self.data.len() as u32 }
     pub fn offset64(&self) -> u64 { self.data.len() as u64 }
 
+    /* convert the given u16 value to the byte order for storing in memory.
+    it works in reverse: convert word in memory from byte order */
+    fn order_u16(&self, value: u16) -> u16
+    {
+        match self.ordering
+        {
+            Ordering::LittleEndian => value.to_le(),
+            Ordering::BigEndian => value.to_be()
+        }
+    }
+
     /* convert the given u32 value to the byte order for storing in memory.
     it works in reverse: convert word in memory from byte order */
     fn order_u32(&self, value: u32) -> u32
@@ -103,6 +163,17 @@ impl Bytes
         }
     }
 
+    /* convert the given u128 value to the byte order for storing in memory.
+    it works in reverse: convert word in memory from byte order */
+    fn order_u128(&self, value: u128) -> u128
+    {
+        match self.ordering
+        {
+            Ordering::LittleEndian => value.to_le(),
+            Ordering::BigEndian => value.to_be()
+        }
+    }
+
     /* add a string as a series of bytes. will not add a null terminator!
     do this yourself using add_null_terminator(), or use add_null_term_string() */
     pub fn add_string(&mut self, to_add: &str)
@@ -140,93 +211,112 @@ impl Bytes
     /* add a byte to the end of the array */
     pub fn add_u8(&mut self, value: u8) { self.data.push(value) }
 
+    /* add a signed byte to the end of the array */
+    pub fn add_i8(&mut self, value: i8) { self.add_u8(value as u8) }
+
+    /* add a 16-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_u16(&mut self, value: u16)
+    {
+        let value = self.order_u16(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 16-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_i16(&mut self, value: i16) { self.add_u16(value as u16) }
+
     /* add a 32-bit word to the end of the array.
     value = word to write into memory using array's byte ordering */
     pub fn add_u32(&mut self, value: u32)
     {
         let value = self.order_u32(value);
-        self.add_u8(((value >>  0) & 0xff) as u8);
-        self.add_u8(((value >>  8) & 0xff) as u8);
-        self.add_u8(((value >> 16) & 0xff) as u8);
-        self.add_u8(((value >> 24) & 0xff) as u8);
+        self.data.extend_from_slice(&value.to_ne_bytes());
     }
 
+    /* add a signed 32-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_i32(&mut self, value: i32) { self.add_u32(value as u32) }
+
     /* add a 64-bit word to the end of the array.
     value = word to write into memory using array's byte ordering */
     pub fn add_u64(&mut self, value: u64)
     {
         let value = self.order_u64(value);
-        self.add_u8(((value >>  0) & 0xff) as u8);
-        self.add_u8(((value >>  8) & 0xff) as u8);
-        self.add_u8(((value >> 16) & 0xff) as u8);
-        self.add_u8(((value >> 24) & 0xff) as u8);
-        self.add_u8(((value >> 32) & 0xff) as u8);
-        self.add_u8(((value >> 40) & 0xff) as u8);
-        self.add_u8(((value >> 48) & 0xff) as u8);
-        self.add_u8(((value >> 56) & 0xff) as u8);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 64-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_i64(&mut self, value: i64) { self.add_u64(value as u64) }
+
+    /* add a 128-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_u128(&mut self, value: u128)
+    {
+        let value = self.order_u128(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
     }
 
+    /* add a signed 128-bit word to the end of the array.
+    value = word to write into memory using array's byte ordering */
+    pub fn add_i128(&mut self, value: i128) { self.add_u128(value as u128) }
+
     /* read a byte from the given byte offset,
     or None if offset is out of bounds */
     pub fn read_u8(&self, offset: usize) -> Option<u8>
     {
-        match self.data.get(offset)
-        {
-            Some(byte) => Some(*byte),
-            None => None
-        }
+        self.data.get(offset).copied()
     }
 
-    /* read a 32-bit word from the given byte offset, 
+    /* read a signed byte from the given byte offset,
+    or None if offset is out of bounds */
+    pub fn read_i8(&self, offset: usize) -> Option<i8> { self.read_u8(offset).map(|v| v as i8) }
+
+    /* read a 16-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_u16(&self, offset: usize) -> Option<u16>
+    {
+        self.data.get(offset..(offset + size_of::<u16>())).map(|bytes| self.order_u16(u16::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 16-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_i16(&self, offset: usize) -> Option<i16> { self.read_u16(offset).map(|v| v as i16) }
+
+    /* read a 32-bit word from the given byte offset,
     using the array's byte ordering. returns None if offset is out of bounds */
     pub fn read_u32(&self, offset: usize) -> Option<u32>
     {
-        match self.data.get(offset..(offset + size_of::<u32>()))
-        {
-            Some(bytes) =>
-            {
-                return Some
-                (
-                    self.order_u32
-                    (
-                        (bytes[0] as u32) <<  0 |
-                        (bytes[1] as u32) <<  8 |
-                        (bytes[2] as u32) << 16 |
-                        (bytes[3] as u32) << 24
-                    )
-                )
-            },
-            None => return None
-        }
+        self.data.get(offset..(offset + size_of::<u32>())).map(|bytes| self.order_u32(u32::from_ne_bytes(bytes.try_into().unwrap())))
     }
 
-    /* read a 64-bit word from the given byte offset, 
+    /* read a signed 32-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_i32(&self, offset: usize) -> Option<i32> { self.read_u32(offset).map(|v| v as i32) }
+
+    /* read a 64-bit word from the given byte offset,
     using the array's byte ordering. returns None if offset is out of bounds */
     pub fn read_u64(&self, offset: usize) -> Option<u64>
     {
-        match self.data.get(offset..(offset + size_of::<u64>()))
-        {
-            Some(bytes) =>
-            {
-                return Some
-                (
-                    self.order_u64
-                    (
-                        (bytes[0] as u64) <<  0 |
-                        (bytes[1] as u64) <<  8 |
-                        (bytes[2] as u64) << 16 |
-                        (bytes[3] as u64) << 24 |
-                        (bytes[4] as u64) << 32 |
-                        (bytes[5] as u64) << 40 |
-                        (bytes[6] as u64) << 48 |
-                        (bytes[7] as u64) << 56
-                    )
-                )
-            },
-            None => return None
-        }
+        self.data.get(offset..(offset + size_of::<u64>())).map(|bytes| self.order_u64(u64::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 64-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_i64(&self, offset: usize) -> Option<i64> { self.read_u64(offset).map(|v| v as i64) }
+
+    /* read a 128-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_u128(&self, offset: usize) -> Option<u128>
+    {
+        self.data.get(offset..(offset + size_of::<u128>())).map(|bytes| self.order_u128(u128::from_ne_bytes(bytes.try_into().unwrap())))
     }
 
+    /* read a signed 128-bit word from the given byte offset,
+    using the array's byte ordering. returns None if offset is out of bounds */
+    pub fn read_i128(&self, offset: usize) -> Option<i128> { self.read_u128(offset).map(|v| v as i128) }
+
     /* alter a byte in the array at the given offset.
     returns true if successful, or false if out of bounds */
     pub fn alter_u8(&mut self, offset: usize, new_value: u8) -> bool
@@ -242,6 +332,32 @@ impl Bytes
         }
     }
 
+    /* alter a signed byte in the array at the given offset.
+    returns true if successful, or false if out of bounds */
+    pub fn alter_i8(&mut self, offset: usize, new_value: i8) -> bool { self.alter_u8(offset, new_value as u8) }
+
+    /* alter a 16-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_u16(&mut self, offset: usize, new_value: u16) -> bool
+    {
+        let new_value = self.order_u16(new_value);
+        match self.data.get_mut(offset..(offset + size_of::<u16>()))
+        {
+            Some(ptr) =>
+            {
+                ptr.copy_from_slice(&new_value.to_ne_bytes());
+                true
+            },
+            None => false
+        }
+    }
+
+    /* alter a signed 16-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_i16(&mut self, offset: usize, new_value: i16) -> bool { self.alter_u16(offset, new_value as u16) }
+
     /* alter a 32-bit word in the array at the given offset.
     new_value = word to write into memory using array's ordering
     returns true if successful, or false if out of bounds */
@@ -251,18 +367,19 @@ impl Bytes
         match self.data.get_mut(offset..(offset + size_of::<u32>()))
         {
             Some(ptr) =>
-            {                
-                ptr[0] = ((new_value >>  0) & 0xff) as u8;
-                ptr[1] = ((new_value >>  8) & 0xff) as u8;
-                ptr[2] = ((new_value >> 16) & 0xff) as u8;
-                ptr[3] = ((new_value >> 24) & 0xff) as u8;
-
+            {
+                ptr.copy_from_slice(&new_value.to_ne_bytes());
                 true
             },
             None => false
-        }           
+        }
     }
 
+    /* alter a signed 32-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_i32(&mut self, offset: usize, new_value: i32) -> bool { self.alter_u32(offset, new_value as u32) }
+
     /* alter a 64-bit word in the array at the given offset.
     new_value = word to write into memory using array's ordering
     returns true if successful, or false if out of bounds */
@@ -272,19 +389,381 @@ impl Bytes
         match self.data.get_mut(offset..(offset + size_of::<u64>()))
         {
             Some(ptr) =>
-            {                
-                ptr[0] = ((new_value >>  0) & 0xff) as u8;
-                ptr[1] = ((new_value >>  8) & 0xff) as u8;
-                ptr[2] = ((new_value >> 16) & 0xff) as u8;
-                ptr[3] = ((new_value >> 24) & 0xff) as u8;
-                ptr[4] = ((new_value >> 32) & 0xff) as u8;
-                ptr[5] = ((new_value >> 40) & 0xff) as u8;
-                ptr[6] = ((new_value >> 48) & 0xff) as u8;
-                ptr[7] = ((new_value >> 56) & 0xff) as u8;
+            {
+                ptr.copy_from_slice(&new_value.to_ne_bytes());
+                true
+            },
+            None => false
+        }
+    }
+
+    /* alter a signed 64-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_i64(&mut self, offset: usize, new_value: i64) -> bool { self.alter_u64(offset, new_value as u64) }
 
+    /* alter a 128-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_u128(&mut self, offset: usize, new_value: u128) -> bool
+    {
+        let new_value = self.order_u128(new_value);
+        match self.data.get_mut(offset..(offset + size_of::<u128>()))
+        {
+            Some(ptr) =>
+            {
+                ptr.copy_from_slice(&new_value.to_ne_bytes());
                 true
             },
             None => false
-        }           
+        }
+    }
+
+    /* alter a signed 128-bit word in the array at the given offset.
+    new_value = word to write into memory using array's ordering
+    returns true if successful, or false if out of bounds */
+    pub fn alter_i128(&mut self, offset: usize, new_value: i128) -> bool { self.alter_u128(offset, new_value as u128) }
+
+    /* add the low nbytes bytes of value to the end of the array, using the array's byte
+    ordering. nbytes must be between 1 and 8 inclusive, for formats with fields that don't
+    fit one of the fixed widths above (eg: 3-, 5- or 6-byte timestamps) */
+    pub fn add_uint(&mut self, value: u64, nbytes: usize)
+    {
+        assert!((1..=8).contains(&nbytes));
+        match self.ordering
+        {
+            Ordering::LittleEndian =>
+            {
+                for i in 0..nbytes
+                {
+                    self.add_u8(((value >> (8 * i)) & 0xff) as u8);
+                }
+            },
+            Ordering::BigEndian =>
+            {
+                for i in (0..nbytes).rev()
+                {
+                    self.add_u8(((value >> (8 * i)) & 0xff) as u8);
+                }
+            }
+        }
+    }
+
+    /* add the low nbytes bytes of a signed value to the end of the array, using the
+    array's byte ordering. nbytes must be between 1 and 8 inclusive */
+    pub fn add_int(&mut self, value: i64, nbytes: usize) { self.add_uint(value as u64, nbytes) }
+
+    /* read nbytes bytes from the given byte offset into a u64, using the array's byte
+    ordering. nbytes must be between 1 and 8 inclusive. returns None if the read would
+    run past the end of the array */
+    pub fn read_uint(&self, offset: usize, nbytes: usize) -> Option<u64>
+    {
+        assert!((1..=8).contains(&nbytes));
+        self.data.get(offset..(offset + nbytes)).map(|bytes|
+        {
+            let mut value: u64 = 0;
+            match self.ordering
+            {
+                Ordering::LittleEndian =>
+                {
+                    for (i, byte) in bytes.iter().enumerate().take(nbytes)
+                    {
+                        value |= (*byte as u64) << (8 * i);
+                    }
+                },
+                Ordering::BigEndian =>
+                {
+                    for (i, byte) in bytes.iter().enumerate().take(nbytes)
+                    {
+                        value |= (*byte as u64) << (8 * (nbytes - 1 - i));
+                    }
+                }
+            }
+            value
+        })
+    }
+
+    /* read nbytes bytes from the given byte offset into an i64, using the array's byte
+    ordering, sign-extending from the top bit of the highest byte read. nbytes must be
+    between 1 and 8 inclusive. returns None if the read would run past the end of the array */
+    pub fn read_int(&self, offset: usize, nbytes: usize) -> Option<i64>
+    {
+        self.read_uint(offset, nbytes).map(|value|
+        {
+            let shift = 64 - (8 * nbytes);
+            ((value << shift) as i64) >> shift
+        })
+    }
+
+    /* add a 32-bit IEEE-754 float to the end of the array, using the array's byte ordering */
+    pub fn add_f32(&mut self, value: f32) { self.add_u32(value.to_bits()) }
+
+    /* add a 64-bit IEEE-754 float to the end of the array, using the array's byte ordering */
+    pub fn add_f64(&mut self, value: f64) { self.add_u64(value.to_bits()) }
+
+    /* read a 32-bit IEEE-754 float from the given byte offset, using the array's byte
+    ordering. returns None if offset is out of bounds */
+    pub fn read_f32(&self, offset: usize) -> Option<f32> { self.read_u32(offset).map(f32::from_bits) }
+
+    /* read a 64-bit IEEE-754 float from the given byte offset, using the array's byte
+    ordering. returns None if offset is out of bounds */
+    pub fn read_f64(&self, offset: usize) -> Option<f64> { self.read_u64(offset).map(f64::from_bits) }
+
+    /* create a cursor over this array, starting at offset zero, for reading values
+    sequentially without having to track byte offsets by hand */
+    pub fn cursor(&self) -> Cursor<'_> { Cursor { bytes: self, pos: 0 } }
+
+    /* add a 16-bit word to the end of the array using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting */
+    pub fn add_u16_as<E: ByteOrder>(&mut self, value: u16)
+    {
+        let value = E::order_u16(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 16-bit word to the end of the array using a compile-time byte order E */
+    pub fn add_i16_as<E: ByteOrder>(&mut self, value: i16) { self.add_u16_as::<E>(value as u16) }
+
+    /* add a 32-bit word to the end of the array using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting */
+    pub fn add_u32_as<E: ByteOrder>(&mut self, value: u32)
+    {
+        let value = E::order_u32(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 32-bit word to the end of the array using a compile-time byte order E */
+    pub fn add_i32_as<E: ByteOrder>(&mut self, value: i32) { self.add_u32_as::<E>(value as u32) }
+
+    /* add a 64-bit word to the end of the array using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting */
+    pub fn add_u64_as<E: ByteOrder>(&mut self, value: u64)
+    {
+        let value = E::order_u64(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 64-bit word to the end of the array using a compile-time byte order E */
+    pub fn add_i64_as<E: ByteOrder>(&mut self, value: i64) { self.add_u64_as::<E>(value as u64) }
+
+    /* add a 128-bit word to the end of the array using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting */
+    pub fn add_u128_as<E: ByteOrder>(&mut self, value: u128)
+    {
+        let value = E::order_u128(value);
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    /* add a signed 128-bit word to the end of the array using a compile-time byte order E */
+    pub fn add_i128_as<E: ByteOrder>(&mut self, value: i128) { self.add_u128_as::<E>(value as u128) }
+
+    /* read a 16-bit word from the given byte offset using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting. returns None if offset is out of bounds */
+    pub fn read_u16_as<E: ByteOrder>(&self, offset: usize) -> Option<u16>
+    {
+        self.data.get(offset..(offset + size_of::<u16>())).map(|bytes| E::order_u16(u16::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 16-bit word from the given byte offset using a compile-time byte order E */
+    pub fn read_i16_as<E: ByteOrder>(&self, offset: usize) -> Option<i16> { self.read_u16_as::<E>(offset).map(|v| v as i16) }
+
+    /* read a 32-bit word from the given byte offset using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting. returns None if offset is out of bounds */
+    pub fn read_u32_as<E: ByteOrder>(&self, offset: usize) -> Option<u32>
+    {
+        self.data.get(offset..(offset + size_of::<u32>())).map(|bytes| E::order_u32(u32::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 32-bit word from the given byte offset using a compile-time byte order E */
+    pub fn read_i32_as<E: ByteOrder>(&self, offset: usize) -> Option<i32> { self.read_u32_as::<E>(offset).map(|v| v as i32) }
+
+    /* read a 64-bit word from the given byte offset using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting. returns None if offset is out of bounds */
+    pub fn read_u64_as<E: ByteOrder>(&self, offset: usize) -> Option<u64>
+    {
+        self.data.get(offset..(offset + size_of::<u64>())).map(|bytes| E::order_u64(u64::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 64-bit word from the given byte offset using a compile-time byte order E */
+    pub fn read_i64_as<E: ByteOrder>(&self, offset: usize) -> Option<i64> { self.read_u64_as::<E>(offset).map(|v| v as i64) }
+
+    /* read a 128-bit word from the given byte offset using a compile-time byte order E,
+    ignoring this array's runtime Ordering setting. returns None if offset is out of bounds */
+    pub fn read_u128_as<E: ByteOrder>(&self, offset: usize) -> Option<u128>
+    {
+        self.data.get(offset..(offset + size_of::<u128>())).map(|bytes| E::order_u128(u128::from_ne_bytes(bytes.try_into().unwrap())))
+    }
+
+    /* read a signed 128-bit word from the given byte offset using a compile-time byte order E */
+    pub fn read_i128_as<E: ByteOrder>(&self, offset: usize) -> Option<i128> { self.read_u128_as::<E>(offset).map(|v| v as i128) }
+
+    /* add a fixed-width big-integer-style byte field to the end of the array, applying the
+    array's byte ordering. bytes is expected in big-endian (most significant byte first)
+    normalized order; it is stored as given for BigEndian, or reversed for LittleEndian.
+    this round-trips values wider than the 128-bit fixed-width methods above, eg: 256-bit
+    crypto scalars or other arbitrary fixed-width bignums */
+    pub fn add_bytes_ordered(&mut self, bytes: &[u8])
+    {
+        match self.ordering
+        {
+            Ordering::BigEndian => self.data.extend_from_slice(bytes),
+            Ordering::LittleEndian => self.data.extend(bytes.iter().rev())
+        }
+    }
+
+    /* read a len-byte big-integer-style field from the given byte offset, applying the
+    array's byte ordering, and return it normalized to big-endian (most significant byte
+    first) order. returns None if the read would run past the end of the array */
+    pub fn read_bytes_ordered(&self, offset: usize, len: usize) -> Option<Vec<u8>>
+    {
+        self.data.get(offset..(offset + len)).map(|bytes|
+            match self.ordering
+            {
+                Ordering::BigEndian => bytes.to_vec(),
+                Ordering::LittleEndian => bytes.iter().rev().cloned().collect()
+            })
+    }
+}
+
+/* Bytes::new() takes no arguments, so it can also be built via Default */
+impl Default for Bytes
+{
+    fn default() -> Self { Self::new() }
+}
+
+/* walks sequentially through a Bytes array, advancing its position as values are read.
+built from Bytes::cursor(). reads return None once the cursor runs past the end of the array */
+pub struct Cursor<'a>
+{
+    bytes: &'a Bytes,
+    pos: usize
+}
+
+impl<'a> Cursor<'a>
+{
+    /* move the cursor to the given byte offset */
+    pub fn seek(&mut self, pos: usize) { self.pos = pos }
+
+    /* move the cursor forward by the given number of bytes */
+    pub fn skip(&mut self, nbytes: usize) { self.pos += nbytes }
+
+    /* return the cursor's current byte offset */
+    pub fn position(&self) -> usize { self.pos }
+
+    /* return the number of bytes left to read before the end of the array */
+    pub fn remaining(&self) -> usize { self.bytes.len().saturating_sub(self.pos) }
+
+    /* read the next byte and advance the cursor, or return None at end-of-buffer */
+    pub fn next_u8(&mut self) -> Option<u8>
+    {
+        let value = self.bytes.read_u8(self.pos)?;
+        self.pos += size_of::<u8>();
+        Some(value)
+    }
+
+    /* read the next signed byte and advance the cursor, or return None at end-of-buffer */
+    pub fn next_i8(&mut self) -> Option<i8>
+    {
+        let value = self.bytes.read_i8(self.pos)?;
+        self.pos += size_of::<i8>();
+        Some(value)
+    }
+
+    /* read the next 16-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_u16(&mut self) -> Option<u16>
+    {
+        let value = self.bytes.read_u16(self.pos)?;
+        self.pos += size_of::<u16>();
+        Some(value)
+    }
+
+    /* read the next signed 16-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_i16(&mut self) -> Option<i16>
+    {
+        let value = self.bytes.read_i16(self.pos)?;
+        self.pos += size_of::<i16>();
+        Some(value)
+    }
+
+    /* read the next 32-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_u32(&mut self) -> Option<u32>
+    {
+        let value = self.bytes.read_u32(self.pos)?;
+        self.pos += size_of::<u32>();
+        Some(value)
+    }
+
+    /* read the next signed 32-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_i32(&mut self) -> Option<i32>
+    {
+        let value = self.bytes.read_i32(self.pos)?;
+        self.pos += size_of::<i32>();
+        Some(value)
+    }
+
+    /* read the next 64-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_u64(&mut self) -> Option<u64>
+    {
+        let value = self.bytes.read_u64(self.pos)?;
+        self.pos += size_of::<u64>();
+        Some(value)
+    }
+
+    /* read the next signed 64-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_i64(&mut self) -> Option<i64>
+    {
+        let value = self.bytes.read_i64(self.pos)?;
+        self.pos += size_of::<i64>();
+        Some(value)
+    }
+
+    /* read the next 128-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_u128(&mut self) -> Option<u128>
+    {
+        let value = self.bytes.read_u128(self.pos)?;
+        self.pos += size_of::<u128>();
+        Some(value)
+    }
+
+    /* read the next signed 128-bit word and advance the cursor, or return None at end-of-buffer */
+    pub fn next_i128(&mut self) -> Option<i128>
+    {
+        let value = self.bytes.read_i128(self.pos)?;
+        self.pos += size_of::<i128>();
+        Some(value)
+    }
+
+    /* read the next 32-bit IEEE-754 float and advance the cursor, or return None at end-of-buffer */
+    pub fn next_f32(&mut self) -> Option<f32>
+    {
+        let value = self.bytes.read_f32(self.pos)?;
+        self.pos += size_of::<f32>();
+        Some(value)
+    }
+
+    /* read the next 64-bit IEEE-754 float and advance the cursor, or return None at end-of-buffer */
+    pub fn next_f64(&mut self) -> Option<f64>
+    {
+        let value = self.bytes.read_f64(self.pos)?;
+        self.pos += size_of::<f64>();
+        Some(value)
+    }
+
+    /* read the next nbytes bytes into a u64 and advance the cursor, or return None at
+    end-of-buffer. nbytes must be between 1 and 8 inclusive */
+    pub fn next_uint(&mut self, nbytes: usize) -> Option<u64>
+    {
+        let value = self.bytes.read_uint(self.pos, nbytes)?;
+        self.pos += nbytes;
+        Some(value)
+    }
+
+    /* read the next nbytes bytes into a sign-extended i64 and advance the cursor, or
+    return None at end-of-buffer. nbytes must be between 1 and 8 inclusive */
+    pub fn next_int(&mut self, nbytes: usize) -> Option<i64>
+    {
+        let value = self.bytes.read_int(self.pos, nbytes)?;
+        self.pos += nbytes;
+        Some(value)
     }
 }
\ No newline at end of file
@@ -31,7 +31,7 @@ fn populate_bytes() -> crate::Bytes
 #[test]
 fn as_slice()
 {
-    assert_eq!(populate_bytes().as_slice().len(), BYTE_FILL_SIZE as usize);
+    assert_eq!(populate_bytes().as_slice().len(), BYTE_FILL_SIZE);
 }
 
 #[test]
@@ -39,16 +39,16 @@ fn from_slice()
 {
     let values = [0, 2, 4, 6, 8];
     let b = crate::Bytes::from_slice(&values);
-    for i in 0..values.len()
+    for (i, value) in values.iter().enumerate()
     {
-        assert_eq!(b.read_u8(i).unwrap(), values[i]);
+        assert_eq!(b.read_u8(i).unwrap(), *value);
     }
 }
 
 #[test]
 fn len()
 {
-    assert_eq!(populate_bytes().len(), BYTE_FILL_SIZE as usize);
+    assert_eq!(populate_bytes().len(), BYTE_FILL_SIZE);
 }
 
 #[test]
@@ -56,9 +56,9 @@ fn add_u8()
 {
     let b = populate_bytes();
     let s = b.as_slice();
-    for v in 0..BYTE_FILL_SIZE
+    for (v, byte) in s.iter().enumerate()
     {
-        assert_eq!(s[v], v as u8);
+        assert_eq!(*byte, v as u8);
     }
 }
 
@@ -143,6 +143,361 @@ fn add_u64()
     }
 }
 
+#[test]
+fn add_u16()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_u16(0xaabb);
+        b.add_u16(0x1122);
+
+        let s = b.as_slice();
+
+        match *ordering
+        {
+            crate::Ordering::LittleEndian =>
+            {
+                assert_eq!(s[0], 0xbb);
+                assert_eq!(s[1], 0xaa);
+                assert_eq!(s[2], 0x22);
+                assert_eq!(s[3], 0x11);
+            },
+
+            crate::Ordering::BigEndian =>
+            {
+                assert_eq!(s[0], 0xaa);
+                assert_eq!(s[1], 0xbb);
+                assert_eq!(s[2], 0x11);
+                assert_eq!(s[3], 0x22);
+            }
+        }
+    }
+}
+
+#[test]
+fn add_u128()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_u128(0x00112233445566778899aabbccddeeff);
+
+        let s = b.as_slice();
+
+        match *ordering
+        {
+            crate::Ordering::LittleEndian =>
+            {
+                assert_eq!(s[0], 0xff);
+                assert_eq!(s[15], 0x00);
+            },
+
+            crate::Ordering::BigEndian =>
+            {
+                assert_eq!(s[0], 0x00);
+                assert_eq!(s[15], 0xff);
+            }
+        }
+    }
+}
+
+#[test]
+fn add_signed()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i8(-1);
+    b.add_i16(-1);
+    b.add_i32(-1);
+    b.add_i64(-1);
+    b.add_i128(-1);
+
+    for byte in b.as_slice()
+    {
+        assert_eq!(*byte, 0xff);
+    }
+}
+
+#[test]
+fn read_i8()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i8(-42);
+    assert_eq!(b.read_i8(0).unwrap(), -42);
+}
+
+#[test]
+fn read_i16()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i16(-1234);
+    assert_eq!(b.read_i16(0).unwrap(), -1234);
+}
+
+#[test]
+fn read_i32()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i32(-123456);
+    assert_eq!(b.read_i32(0).unwrap(), -123456);
+}
+
+#[test]
+fn read_i64()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i64(-123456789);
+    assert_eq!(b.read_i64(0).unwrap(), -123456789);
+}
+
+#[test]
+fn read_u128()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_u128(0x00112233445566778899aabbccddeeff);
+        assert_eq!(b.read_u128(0).unwrap(), 0x00112233445566778899aabbccddeeff);
+    }
+}
+
+#[test]
+fn read_i128()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i128(-123456789);
+    assert_eq!(b.read_i128(0).unwrap(), -123456789);
+}
+
+#[test]
+fn alter_i8()
+{
+    let mut b = crate::Bytes::new();
+    b.add_i8(0);
+    assert!(b.alter_i8(0, -5));
+    assert_eq!(b.read_i8(0).unwrap(), -5);
+}
+
+#[test]
+fn alter_u16()
+{
+    let words: [u16; 4] = [ 0x1122, 0x5566, 0x99aa, 0xddee ];
+    let new_value = 0xff00;
+
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        for w in &words
+        {
+            b.add_u16(*w);
+        }
+
+        for i in 0..words.len()
+        {
+            assert!(b.alter_u16(i * size_of::<u16>(), new_value));
+        }
+
+        for i in 0..words.len()
+        {
+            assert_eq!(b.read_u16(i * size_of::<u16>()).unwrap(), new_value);
+        }
+    }
+}
+
+#[test]
+fn alter_u128()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u128(0);
+    assert!(b.alter_u128(0, 0x00112233445566778899aabbccddeeff));
+    assert_eq!(b.read_u128(0).unwrap(), 0x00112233445566778899aabbccddeeff);
+}
+
+#[test]
+fn add_read_uint()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_uint(0x112233, 3);
+        b.add_uint(0x44556677, 4);
+
+        assert_eq!(b.len(), 7);
+        assert_eq!(b.read_uint(0, 3).unwrap(), 0x112233);
+        assert_eq!(b.read_uint(3, 4).unwrap(), 0x44556677);
+        assert_eq!(b.read_uint(7, 1), None);
+    }
+}
+
+#[test]
+fn add_read_int()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_int(-1, 3);
+        b.add_int(-42, 5);
+
+        assert_eq!(b.read_int(0, 3).unwrap(), -1);
+        assert_eq!(b.read_int(3, 5).unwrap(), -42);
+    }
+}
+
+#[test]
+#[should_panic]
+fn read_uint_rejects_zero_nbytes()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u8(0x11);
+    b.read_uint(0, 0);
+}
+
+#[test]
+#[should_panic]
+fn read_int_rejects_zero_nbytes()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u8(0x11);
+    b.read_int(0, 0);
+}
+
+#[test]
+fn add_read_f32()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_f32(3.14158);
+        b.add_f32(-2.5);
+
+        assert_eq!(b.read_f32(0).unwrap(), 3.14158);
+        assert_eq!(b.read_f32(4).unwrap(), -2.5);
+    }
+}
+
+#[test]
+fn add_read_f64()
+{
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_f64(3.14159265358978);
+        b.add_f64(-2.5);
+
+        assert_eq!(b.read_f64(0).unwrap(), 3.14159265358978);
+        assert_eq!(b.read_f64(8).unwrap(), -2.5);
+    }
+}
+
+#[test]
+fn cursor_walks_sequentially()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u8(0x11);
+    b.add_u16(0x2233);
+    b.add_u32(0x44556677);
+    b.add_i8(-1);
+
+    let mut c = b.cursor();
+    assert_eq!(c.remaining(), b.len());
+    assert_eq!(c.next_u8().unwrap(), 0x11);
+    assert_eq!(c.next_u16().unwrap(), 0x2233);
+    assert_eq!(c.next_u32().unwrap(), 0x44556677);
+    assert_eq!(c.next_i8().unwrap(), -1);
+    assert_eq!(c.remaining(), 0);
+    assert_eq!(c.next_u8(), None);
+}
+
+#[test]
+fn cursor_seek_and_skip()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u8(0x11);
+    b.add_u8(0x22);
+    b.add_u8(0x33);
+
+    let mut c = b.cursor();
+    c.skip(1);
+    assert_eq!(c.position(), 1);
+    assert_eq!(c.next_u8().unwrap(), 0x22);
+
+    c.seek(0);
+    assert_eq!(c.next_u8().unwrap(), 0x11);
+}
+
+#[test]
+fn add_read_u32_as()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u32_as::<crate::LittleEndian>(0xaabbccdd);
+    b.add_u32_as::<crate::BigEndian>(0x11223344);
+
+    let s = b.as_slice();
+    assert_eq!(s[0], 0xdd);
+    assert_eq!(s[1], 0xcc);
+    assert_eq!(s[2], 0xbb);
+    assert_eq!(s[3], 0xaa);
+    assert_eq!(s[4], 0x11);
+    assert_eq!(s[5], 0x22);
+    assert_eq!(s[6], 0x33);
+    assert_eq!(s[7], 0x44);
+
+    assert_eq!(b.read_u32_as::<crate::LittleEndian>(0).unwrap(), 0xaabbccdd);
+    assert_eq!(b.read_u32_as::<crate::BigEndian>(4).unwrap(), 0x11223344);
+}
+
+#[test]
+fn network_endian_is_big_endian()
+{
+    let mut b = crate::Bytes::new();
+    b.add_u16_as::<crate::NetworkEndian>(0x1122);
+    assert_eq!(b.as_slice(), &[0x11, 0x22]);
+}
+
+#[test]
+fn add_read_bytes_ordered()
+{
+    /* a 256-bit value, normalized to big-endian (most significant byte first) */
+    let scalar: [u8; 32] =
+    [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+        0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f
+    ];
+
+    for ordering in &ORDERINGS
+    {
+        let mut b = crate::Bytes::new();
+        b.set_ordering(*ordering);
+        b.add_bytes_ordered(&scalar);
+
+        assert_eq!(b.len(), scalar.len());
+
+        match *ordering
+        {
+            crate::Ordering::BigEndian => assert_eq!(b.as_slice(), &scalar[..]),
+            crate::Ordering::LittleEndian =>
+            {
+                let mut reversed = scalar.to_vec();
+                reversed.reverse();
+                assert_eq!(b.as_slice(), reversed.as_slice());
+            }
+        }
+
+        assert_eq!(b.read_bytes_ordered(0, scalar.len()).unwrap(), scalar.to_vec());
+        assert_eq!(b.read_bytes_ordered(0, scalar.len() + 1), None);
+    }
+}
+
 #[test]
 fn read_u8()
 {
@@ -225,7 +580,7 @@ fn alter_u8()
     for i in 0..BYTE_FILL_SIZE
     {
         let new_value = (BYTE_FILL_SIZE - i) as u8;
-        assert_eq!(b.alter_u8(i, new_value), true);
+        assert!(b.alter_u8(i, new_value));
         assert_eq!(b.read_u8(i).unwrap(), new_value);
     }
 }
@@ -250,7 +605,7 @@ fn alter_u32()
 
         for i in 0..words.len()
         {
-            assert_eq!(b.alter_u32(i * size_of::<u32>(),  new_value), true);
+            assert!(b.alter_u32(i * size_of::<u32>(),  new_value));
         }
 
         for i in 0..words.len()
@@ -280,7 +635,7 @@ fn alter_u64()
 
         for i in 0..words.len()
         {
-            assert_eq!(b.alter_u64(i * size_of::<u64>(),  new_value), true);
+            assert!(b.alter_u64(i * size_of::<u64>(),  new_value));
         }
 
         for i in 0..words.len()